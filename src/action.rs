@@ -0,0 +1,35 @@
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Direction {
+    Up,
+    Down,
+    Right,
+    Left,
+}
+
+impl Direction {
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+/// Semantic events produced by the input task and the tick timer, and
+/// consumed by the main loop over a single channel. Keeping these separate
+/// from raw `crossterm` events is what lets the input task stay responsive
+/// regardless of how fast the snake is currently moving.
+pub enum Action {
+    Turn(Direction),
+    Restart,
+    Quit,
+    Tick,
+    Char(char),
+    Backspace,
+    Confirm,
+    ToggleWrapMode,
+    ToggleAutopilot,
+    Resize(u16, u16),
+}
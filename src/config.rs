@@ -0,0 +1,92 @@
+use crossterm::style::Color;
+use serde::Deserialize;
+use std::path::PathBuf;
+
+const DEFAULT_MIN_MOVE_DELAY: u64 = 50;
+const DEFAULT_MAX_MOVE_DELAY: u64 = 150;
+const DEFAULT_START_LENGTH: u16 = 2;
+
+/// A `crossterm::style::Color` by name, so config files can spell colors as
+/// plain strings (`"red"`, `"dark_grey"`, ...) instead of reaching into
+/// crossterm's own enum.
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigColor {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+    DarkGrey,
+    Reset,
+}
+
+impl From<ConfigColor> for Color {
+    fn from(color: ConfigColor) -> Self {
+        match color {
+            ConfigColor::Black => Color::Black,
+            ConfigColor::Red => Color::Red,
+            ConfigColor::Green => Color::Green,
+            ConfigColor::Yellow => Color::Yellow,
+            ConfigColor::Blue => Color::Blue,
+            ConfigColor::Magenta => Color::Magenta,
+            ConfigColor::Cyan => Color::Cyan,
+            ConfigColor::White => Color::White,
+            ConfigColor::Grey => Color::Grey,
+            ConfigColor::DarkGrey => Color::DarkGrey,
+            ConfigColor::Reset => Color::Reset,
+        }
+    }
+}
+
+/// User-tunable game settings, loaded from `~/.config/snake/config.json5`.
+/// Any field left out of the file (or the file itself being absent or
+/// malformed) falls back to the value in [`Config::default`].
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub min_move_delay: u64,
+    pub max_move_delay: u64,
+    pub start_length: u16,
+    pub wrap: bool,
+    pub snake_color: ConfigColor,
+    pub target_color: ConfigColor,
+    pub head_glyph: char,
+    pub body_glyph: char,
+    pub target_glyph: char,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            min_move_delay: DEFAULT_MIN_MOVE_DELAY,
+            max_move_delay: DEFAULT_MAX_MOVE_DELAY,
+            start_length: DEFAULT_START_LENGTH,
+            wrap: true,
+            snake_color: ConfigColor::Reset,
+            target_color: ConfigColor::Reset,
+            head_glyph: '◍',
+            body_glyph: '●',
+            target_glyph: '●',
+        }
+    }
+}
+
+impl Config {
+    /// Loads the config file, falling back to [`Config::default`] if it is
+    /// missing, unreadable, or fails to parse.
+    pub fn load() -> Self {
+        Self::path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|contents| json5::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("snake").join("config.json5"))
+    }
+}
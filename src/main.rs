@@ -1,11 +1,19 @@
-use crate::Direction::*;
+mod action;
+mod config;
+mod scores;
+
+use action::Direction::*;
+use action::{Action, Direction};
 use anyhow::Result;
+use config::Config;
 use rand::Rng;
+use scores::Leaderboard;
 use std::{
+    collections::{HashMap, HashSet, VecDeque},
     io::{stdout, Stdout, Write},
-    thread::sleep,
     time::Duration,
 };
+use tokio::sync::{mpsc, watch};
 
 use crossterm::{
     cursor,
@@ -16,35 +24,79 @@ use crossterm::{
         disable_raw_mode, enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen,
         LeaveAlternateScreen,
     },
-    ExecutableCommand, QueueableCommand,
+    QueueableCommand,
 };
 
-const MAX_MOVE_DELAY: u64 = 150;
-const MIN_MOVE_DELAY: u64 = 50;
+const MAX_QUEUED_DIRECTIONS: usize = 8;
+const MAX_NAME_LEN: usize = 16;
+const MIN_TERMINAL_WIDTH: u16 = 40;
+const MIN_TERMINAL_HEIGHT: u16 = 20;
 
 #[derive(PartialEq, Clone, Copy)]
-enum Direction {
-    Up,
-    Down,
-    Right,
-    Left,
+enum WrapMode {
+    Wrap,
+    Walls,
+}
+
+impl WrapMode {
+    fn from_config(wrap: bool) -> Self {
+        if wrap {
+            WrapMode::Wrap
+        } else {
+            WrapMode::Walls
+        }
+    }
+
+    fn toggled(self) -> Self {
+        match self {
+            WrapMode::Wrap => WrapMode::Walls,
+            WrapMode::Walls => WrapMode::Wrap,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            WrapMode::Wrap => "wrap",
+            WrapMode::Walls => "walls",
+        }
+    }
 }
 
 struct Snake {
     body: Vec<(u16, u16)>,
     head_dir: Direction,
+    pending_dirs: VecDeque<Direction>,
     score: u64,
 }
 
 impl Snake {
-    fn new(head_x: u16, head_y: u16) -> Self {
-        let (tail_x, tail_y) = (head_x + 1, head_y);
+    /// `field_w`/`field_h` are the usable play-field dimensions, used to
+    /// keep a `start_length` from a user's config file (unbounded, since it
+    /// comes straight off disk) from overflowing the `head_x + offset`
+    /// arithmetic or placing most of the body off-screen.
+    fn new(head_x: u16, head_y: u16, start_length: u16, field_w: u16, field_h: u16) -> Self {
+        let start_length = start_length.clamp(1, field_w.min(field_h).max(1));
+        let body = (0..start_length)
+            .map(|offset| (head_x + offset, head_y))
+            .collect();
         Self {
-            body: vec![(head_x, head_y), (tail_x, tail_y)],
+            body,
             head_dir: Left,
+            pending_dirs: VecDeque::with_capacity(MAX_QUEUED_DIRECTIONS),
             score: 0,
         }
     }
+
+    fn queue_turn(&mut self, dir: Direction) {
+        let last = self.pending_dirs.back().copied().unwrap_or(self.head_dir);
+        if dir == last.opposite() || dir == last {
+            return;
+        }
+        if self.pending_dirs.len() >= MAX_QUEUED_DIRECTIONS {
+            return;
+        }
+        self.pending_dirs.push_back(dir);
+    }
 }
 
 struct Target {
@@ -70,39 +122,138 @@ struct World {
     target: Target,
     update_target_position: bool,
     game_over: bool,
+    banner_shown: bool,
     stdout: Stdout,
+    config: Config,
+    scores: Leaderboard,
+    entering_name: bool,
+    name_buffer: String,
+    wrap_mode: WrapMode,
+    wall_collision: bool,
+    autopilot: bool,
+    too_small: bool,
 }
 
 impl World {
-    fn new(max_x: u16, max_y: u16) -> Self {
+    fn new(max_x: u16, max_y: u16, config: Config, scores: Leaderboard) -> Self {
         Self {
             min_x: 2,
             max_x: max_x - 2,
             min_y: 2,
             max_y: max_y - 2,
-            snake: Snake::new(max_x / 2, max_y / 2),
+            snake: Snake::new(
+                max_x / 2,
+                max_y / 2,
+                config.start_length,
+                max_x - 4,
+                max_y - 4,
+            ),
             target: Target::new(3, max_x - 4, 3, max_y - 4),
             update_target_position: true,
             game_over: false,
+            banner_shown: false,
             stdout: stdout(),
+            wrap_mode: WrapMode::from_config(config.wrap),
+            config,
+            scores,
+            entering_name: false,
+            name_buffer: String::new(),
+            wall_collision: false,
+            autopilot: false,
+            too_small: false,
         }
     }
 
-    fn run(&mut self) -> Result<()> {
-        loop {
-            self.refresh_screen()?;
-            self.process_keypress()?;
-            self.snake_move();
-            if self.check_failure() {
-                self.draw_failure_banner()?;
+    async fn run(&mut self) -> Result<()> {
+        let (action_tx, mut action_rx) = mpsc::channel(32);
+        let (delay_tx, delay_rx) = watch::channel(self.snake_move_delay());
+        let (entering_name_tx, entering_name_rx) = watch::channel(self.entering_name);
+
+        std::thread::spawn({
+            let action_tx = action_tx.clone();
+            move || input_task(action_tx, entering_name_rx)
+        });
+        tokio::spawn(tick_task(action_tx, delay_rx));
+
+        self.refresh_screen()?;
+        while let Some(action) = action_rx.recv().await {
+            match action {
+                Action::Turn(dir) if !self.game_over => self.snake.queue_turn(dir),
+                Action::Turn(_) => {}
+                Action::Restart if !self.entering_name => self.restart(),
+                Action::Restart => {}
+                Action::Quit => break,
+                Action::Char(c) if self.entering_name => {
+                    if self.name_buffer.len() < MAX_NAME_LEN && !c.is_control() {
+                        self.name_buffer.push(c);
+                        self.draw_name_prompt()?;
+                    }
+                }
+                Action::Char(_) => {}
+                Action::Backspace if self.entering_name => {
+                    self.name_buffer.pop();
+                    self.draw_name_prompt()?;
+                }
+                Action::Backspace => {}
+                Action::Confirm if self.entering_name => self.submit_high_score()?,
+                Action::Confirm => {}
+                Action::ToggleWrapMode if !self.entering_name => {
+                    self.wrap_mode = self.wrap_mode.toggled()
+                }
+                Action::ToggleWrapMode => {}
+                Action::ToggleAutopilot if !self.entering_name => self.autopilot = !self.autopilot,
+                Action::ToggleAutopilot => {}
+                Action::Resize(w, h) => self.handle_resize(w, h)?,
+                Action::Tick if self.too_small || self.game_over => {}
+                Action::Tick => {
+                    if self.autopilot {
+                        self.snake.pending_dirs.clear();
+                        if let Some(dir) = self.plan_move() {
+                            self.snake.pending_dirs.push_back(dir);
+                        }
+                    }
+                    self.snake_move();
+                    if self.check_failure() && !self.banner_shown {
+                        self.banner_shown = true;
+                        self.draw_failure_banner()?;
+                        if self.scores.qualifies(self.snake.score) {
+                            self.entering_name = true;
+                            self.draw_name_prompt()?;
+                        } else {
+                            self.draw_leaderboard()?;
+                        }
+                    }
+                    self.check_collision();
+                    self.refresh_screen()?;
+                    delay_tx.send_if_modified(|delay| {
+                        let new_delay = self.snake_move_delay();
+                        let changed = *delay != new_delay;
+                        *delay = new_delay;
+                        changed
+                    });
+                }
             }
-            self.check_collision();
-            sleep(Duration::from_millis(self.snake_move_delay()));
+            entering_name_tx.send_if_modified(|entering| {
+                let changed = *entering != self.entering_name;
+                *entering = self.entering_name;
+                changed
+            });
         }
+        Ok(())
+    }
+
+    fn submit_high_score(&mut self) -> Result<()> {
+        let name = self.name_buffer.trim();
+        let name = if name.is_empty() { "anonymous" } else { name };
+        self.scores.insert(name.to_string(), self.snake.score);
+        self.scores.save()?;
+        self.entering_name = false;
+        self.draw_leaderboard()?;
+        Ok(())
     }
 
     fn refresh_screen(&mut self) -> Result<()> {
-        if !self.game_over {
+        if !self.game_over && !self.too_small {
             self.stdout.queue(Clear(ClearType::All))?;
             self.draw_statusbar()?;
             self.draw_snake()?;
@@ -112,49 +263,75 @@ impl World {
         Ok(())
     }
 
+    /// Recomputes the play field for a new terminal size. Pauses the game
+    /// behind a "window too small" message until the terminal is big enough
+    /// to host the field, statusbar, and failure banner again.
+    fn handle_resize(&mut self, term_w: u16, term_h: u16) -> Result<()> {
+        if term_w < MIN_TERMINAL_WIDTH || term_h < MIN_TERMINAL_HEIGHT {
+            self.too_small = true;
+            self.draw_too_small_banner()?;
+            return Ok(());
+        }
+        self.too_small = false;
+        self.min_x = 2;
+        self.max_x = term_w - 2;
+        self.min_y = 2;
+        self.max_y = term_h - 2;
+
+        for segment in &mut self.snake.body {
+            segment.0 = segment.0.clamp(self.min_x, self.max_x - 1);
+            segment.1 = segment.1.clamp(self.min_y, self.max_y - 1);
+        }
+        // Shrinking the terminal can clamp distinct segments onto the same
+        // cell; drop the duplicates (keeping whichever is closest to the
+        // head, since body is ordered head to tail) so check_failure
+        // doesn't see a false self-collision on the next tick.
+        let mut seen = HashSet::new();
+        self.snake.body.retain(|segment| seen.insert(*segment));
+
+        self.target.x = self.target.x.clamp(self.min_x, self.max_x - 1);
+        self.target.y = self.target.y.clamp(self.min_y, self.max_y - 1);
+        while self.snake.body.contains(&(self.target.x, self.target.y)) {
+            self.target = Target::new(3, self.max_x - 4, 3, self.max_y - 4);
+        }
+
+        self.refresh_screen()
+    }
+
+    fn draw_too_small_banner(&mut self) -> Result<()> {
+        execute!(
+            self.stdout,
+            Clear(ClearType::All),
+            cursor::MoveTo(0, 0),
+            style::Print("window too small, please resize")
+        )?;
+        Ok(())
+    }
+
     fn restart(&mut self) {
-        self.snake = Snake::new(self.max_x / 2, self.max_y / 2);
+        self.snake = Snake::new(
+            self.max_x / 2,
+            self.max_y / 2,
+            self.config.start_length,
+            self.max_x - self.min_x,
+            self.max_y - self.min_y,
+        );
         self.target = Target::new(3, self.max_x - 2, 3, self.max_y - 2);
         self.update_target_position = true;
         self.game_over = false;
-    }
-
-    fn process_keypress(&mut self) -> Result<()> {
-        if let Ok(true) = poll(Duration::from_millis(10)) {
-            let event = read()?;
-            if let Event::Key(key) = event {
-                let cur_dir = self.snake.head_dir;
-                match key.code {
-                    KeyCode::Char('q') => {
-                        self.stdout.execute(cursor::Show).unwrap();
-                        self.stdout.execute(LeaveAlternateScreen).unwrap();
-                        disable_raw_mode().unwrap();
-                        std::process::exit(0);
-                    }
-                    KeyCode::Char('w') if cur_dir != Down && !self.game_over => {
-                        self.snake.head_dir = Up
-                    }
-                    KeyCode::Char('a') if cur_dir != Right && !self.game_over => {
-                        self.snake.head_dir = Left
-                    }
-                    KeyCode::Char('s') if cur_dir != Up && !self.game_over => {
-                        self.snake.head_dir = Down
-                    }
-                    KeyCode::Char('d') if cur_dir != Left && !self.game_over => {
-                        self.snake.head_dir = Right
-                    }
-                    KeyCode::Enter => self.restart(),
-                    _ => {}
-                }
-            }
-        }
-        Ok(())
+        self.banner_shown = false;
+        self.entering_name = false;
+        self.name_buffer.clear();
+        self.wall_collision = false;
     }
 
     fn draw_statusbar(&mut self) -> Result<()> {
+        let autopilot = if self.autopilot { "on" } else { "off" };
         let msg = format!(
-            " press q to exit | moving <w,a,s,d>, restart <enter> | score {} ",
-            self.snake.score
+            " press q to exit | moving <w,a,s,d>, restart <enter>, mode <m>, autopilot <p> | score {} | mode: {} | autopilot: {} ",
+            self.snake.score,
+            self.wrap_mode.label(),
+            autopilot
         );
         self.stdout.queue(cursor::MoveTo(0, self.max_y + 1))?;
         self.stdout.queue(style::Print(msg.black().on_grey()))?;
@@ -162,13 +339,16 @@ impl World {
     }
 
     fn draw_snake(&mut self) -> Result<()> {
+        let color = self.config.snake_color.into();
         let (head_x, head_y) = self.snake.body.first().unwrap();
         self.stdout.queue(cursor::MoveTo(*head_x, *head_y))?;
-        self.stdout.queue(style::Print("◍"))?;
+        self.stdout
+            .queue(style::Print(self.config.head_glyph.with(color)))?;
 
         for (x, y) in &self.snake.body[1..] {
             self.stdout.queue(cursor::MoveTo(*x, *y))?;
-            self.stdout.queue(style::Print("●"))?;
+            self.stdout
+                .queue(style::Print(self.config.body_glyph.with(color)))?;
         }
         Ok(())
     }
@@ -191,7 +371,11 @@ impl World {
         }
         self.stdout
             .queue(cursor::MoveTo(self.target.x, self.target.y))?;
-        self.stdout.queue(style::Print("●"))?;
+        self.stdout.queue(style::Print(
+            self.config
+                .target_glyph
+                .with(self.config.target_color.into()),
+        ))?;
         Ok(())
     }
 
@@ -217,44 +401,183 @@ impl World {
         Ok(())
     }
 
+    fn draw_name_prompt(&mut self) -> Result<()> {
+        let x = (self.max_x / 2) - 12;
+        let y = (self.max_y / 2) + 2;
+        let line = format!(" New high score! Name: {}_", self.name_buffer);
+        execute!(
+            self.stdout,
+            cursor::MoveTo(x, y),
+            Clear(ClearType::CurrentLine),
+            style::Print(line)
+        )?;
+        Ok(())
+    }
+
+    fn draw_leaderboard(&mut self) -> Result<()> {
+        let x = (self.max_x / 2) - 12;
+        let y = (self.max_y / 2) + 2;
+        execute!(
+            self.stdout,
+            cursor::MoveTo(x, y),
+            Clear(ClearType::CurrentLine),
+            style::Print(" Leaderboard")
+        )?;
+        for (i, entry) in self.scores.entries().iter().enumerate() {
+            let y = y + 1 + i as u16;
+            let line = format!(" {:>2}. {:<16} {:>6}", i + 1, entry.name, entry.score);
+            execute!(
+                self.stdout,
+                cursor::MoveTo(x, y),
+                Clear(ClearType::CurrentLine),
+                style::Print(line)
+            )?;
+        }
+        Ok(())
+    }
+
     fn snake_move(&mut self) {
-        self.snake_new_head();
-        self.snake.body.pop();
+        if let Some(dir) = self.snake.pending_dirs.pop_front() {
+            self.snake.head_dir = dir;
+        }
+        if self.snake_new_head() {
+            self.snake.body.pop();
+        }
     }
 
-    fn snake_new_head(&mut self) {
-        let (head_x, head_y) = self.snake.body.first().unwrap();
-        let new_head = match self.snake.head_dir {
-            Up => {
-                if *head_y == self.min_y {
-                    (*head_x, self.max_y - 1)
-                } else {
-                    (*head_x, *head_y - 1)
-                }
+    /// Advances the head in `head_dir` and inserts it at the front of the
+    /// body. In `Walls` mode a step past the field's edge is not taken:
+    /// `wall_collision` is set for `check_failure` and the body is left
+    /// untouched, so the caller must skip the matching tail-pop.
+    fn snake_new_head(&mut self) -> bool {
+        let (head_x, head_y) = *self.snake.body.first().unwrap();
+        match self.step(head_x, head_y, self.snake.head_dir) {
+            Some(new_head) => {
+                self.snake.body.insert(0, new_head);
+                true
+            }
+            None => {
+                self.wall_collision = true;
+                false
+            }
+        }
+    }
+
+    /// One step from `(x, y)` in `dir`, honoring the active `WrapMode`.
+    /// Returns `None` when the step would cross the field's edge in
+    /// `Walls` mode.
+    fn step(&self, x: u16, y: u16, dir: Direction) -> Option<(u16, u16)> {
+        match dir {
+            Up if y == self.min_y => {
+                (self.wrap_mode == WrapMode::Wrap).then_some((x, self.max_y - 1))
             }
-            Down => {
-                if *head_y == self.max_y - 1 {
-                    (*head_x, self.min_y + 1)
-                } else {
-                    (*head_x, *head_y + 1)
+            Up => Some((x, y - 1)),
+            Down if y == self.max_y - 1 => {
+                (self.wrap_mode == WrapMode::Wrap).then_some((x, self.min_y + 1))
+            }
+            Down => Some((x, y + 1)),
+            Left if x == self.min_x => {
+                (self.wrap_mode == WrapMode::Wrap).then_some((self.max_x - 1, y))
+            }
+            Left => Some((x - 1, y)),
+            Right if x == self.max_x - 1 => {
+                (self.wrap_mode == WrapMode::Wrap).then_some((self.min_x + 1, y))
+            }
+            Right => Some((x + 1, y)),
+        }
+    }
+
+    fn neighbors(&self, (x, y): (u16, u16)) -> Vec<(Direction, (u16, u16))> {
+        [Up, Down, Left, Right]
+            .into_iter()
+            .filter_map(|dir| self.step(x, y, dir).map(|next| (dir, next)))
+            .collect()
+    }
+
+    /// Picks the snake's next move in autopilot: a BFS over free cells from
+    /// the head to the target, falling back to whichever free neighbor
+    /// leaves the most reachable space if no path to the target exists.
+    fn plan_move(&self) -> Option<Direction> {
+        let start = *self.snake.body.first().unwrap();
+        let goal = (self.target.x, self.target.y);
+        let blocked: HashSet<(u16, u16)> = self.snake.body[..self.snake.body.len() - 1]
+            .iter()
+            .copied()
+            .collect();
+
+        let mut came_from: HashMap<(u16, u16), ((u16, u16), Direction)> = HashMap::new();
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        visited.insert(start);
+        queue.push_back(start);
+
+        while let Some(cur) = queue.pop_front() {
+            if cur == goal {
+                break;
+            }
+            for (dir, next) in self.neighbors(cur) {
+                if visited.contains(&next) || blocked.contains(&next) {
+                    continue;
                 }
+                visited.insert(next);
+                came_from.insert(next, (cur, dir));
+                queue.push_back(next);
             }
-            Left => {
-                if *head_x == self.min_x {
-                    (self.max_x - 1, *head_y)
-                } else {
-                    (*head_x - 1, *head_y)
+        }
+
+        if visited.contains(&goal) {
+            let mut node = goal;
+            while let Some(&(prev, dir)) = came_from.get(&node) {
+                if prev == start {
+                    return Some(dir);
                 }
+                node = prev;
+            }
+        }
+        self.safest_direction(start, &blocked)
+    }
+
+    /// Fallback when no path to the target exists: move into whichever
+    /// free neighbor of the head has the most reachable free space, to put
+    /// off trapping the snake for as long as possible.
+    fn safest_direction(
+        &self,
+        head: (u16, u16),
+        blocked: &HashSet<(u16, u16)>,
+    ) -> Option<Direction> {
+        let mut best: Option<(Direction, usize)> = None;
+        for dir in [Up, Down, Left, Right] {
+            if dir == self.snake.head_dir.opposite() {
+                continue;
+            }
+            let Some(next) = self.step(head.0, head.1, dir) else {
+                continue;
+            };
+            if blocked.contains(&next) {
+                continue;
+            }
+            let space = self.reachable_count(next, blocked);
+            if best.is_none_or(|(_, best_space)| space > best_space) {
+                best = Some((dir, space));
             }
-            Right => {
-                if *head_x == self.max_x - 1 {
-                    (self.min_x + 1, *head_y)
-                } else {
-                    (*head_x + 1, *head_y)
+        }
+        best.map(|(dir, _)| dir)
+    }
+
+    fn reachable_count(&self, start: (u16, u16), blocked: &HashSet<(u16, u16)>) -> usize {
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        visited.insert(start);
+        queue.push_back(start);
+        while let Some(cur) = queue.pop_front() {
+            for (_, next) in self.neighbors(cur) {
+                if !visited.contains(&next) && !blocked.contains(&next) {
+                    visited.insert(next);
+                    queue.push_back(next);
                 }
             }
-        };
-        self.snake.body.insert(0, new_head);
+        }
+        visited.len()
     }
 
     fn check_collision(&mut self) {
@@ -267,6 +590,9 @@ impl World {
     }
 
     fn check_failure(&self) -> bool {
+        if self.wall_collision {
+            return true;
+        }
         let (head_x, head_y) = self.snake.body.first().unwrap();
         for (x, y) in &self.snake.body[1..] {
             if head_x == x && head_y == y {
@@ -277,23 +603,191 @@ impl World {
     }
 
     fn snake_move_delay(&self) -> u64 {
-        if self.snake.score > MAX_MOVE_DELAY {
-            MAX_MOVE_DELAY
+        let max_delay = self.config.max_move_delay;
+        let min_delay = self.config.min_move_delay;
+        if self.snake.score > max_delay {
+            max_delay
         } else {
-            let n = MAX_MOVE_DELAY - self.snake.score;
-            std::cmp::max(n, MIN_MOVE_DELAY)
+            let n = max_delay - self.snake.score;
+            std::cmp::max(n, min_delay)
         }
     }
 }
 
-fn main() {
+/// Blocks on `poll`/`read` in a dedicated OS thread and forwards semantic
+/// `Action`s over `tx`. Runs independently of the snake's move delay, so
+/// keypresses are never held up waiting on a slow tick. `entering_name`
+/// tracks whether the main loop is currently prompting for a high-score
+/// name, so the same keys can double as free text instead of game moves.
+fn input_task(tx: mpsc::Sender<Action>, entering_name: watch::Receiver<bool>) {
+    loop {
+        if let Ok(true) = poll(Duration::from_millis(10)) {
+            let Ok(event) = read() else { continue };
+            let action = match event {
+                Event::Resize(w, h) => Some(Action::Resize(w, h)),
+                Event::Key(key) if *entering_name.borrow() => match key.code {
+                    KeyCode::Enter => Some(Action::Confirm),
+                    KeyCode::Backspace => Some(Action::Backspace),
+                    KeyCode::Char(c) => Some(Action::Char(c)),
+                    _ => None,
+                },
+                Event::Key(key) => match key.code {
+                    KeyCode::Char('q') => Some(Action::Quit),
+                    KeyCode::Char('w') => Some(Action::Turn(Up)),
+                    KeyCode::Char('a') => Some(Action::Turn(Left)),
+                    KeyCode::Char('s') => Some(Action::Turn(Down)),
+                    KeyCode::Char('d') => Some(Action::Turn(Right)),
+                    KeyCode::Char('m') => Some(Action::ToggleWrapMode),
+                    KeyCode::Char('p') => Some(Action::ToggleAutopilot),
+                    KeyCode::Enter => Some(Action::Restart),
+                    _ => None,
+                },
+                _ => None,
+            };
+            if let Some(action) = action {
+                let is_quit = matches!(action, Action::Quit);
+                if tx.blocking_send(action).is_err() || is_quit {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Sends `Action::Tick` at the interval published on `delay_rx`, which the
+/// main loop updates whenever the snake's speed changes.
+async fn tick_task(tx: mpsc::Sender<Action>, mut delay_rx: watch::Receiver<u64>) {
+    loop {
+        let delay = *delay_rx.borrow();
+        let sleep = tokio::time::sleep(Duration::from_millis(delay));
+        tokio::select! {
+            // A speed change (e.g. the snake growing) restarts the wait at
+            // the new delay instead of waiting out the stale one first.
+            _ = delay_rx.changed() => continue,
+            _ = sleep => {}
+        }
+        if tx.send(Action::Tick).await.is_err() {
+            return;
+        }
+    }
+}
+
+fn print_scores() {
+    let leaderboard = Leaderboard::load();
+    if leaderboard.entries().is_empty() {
+        println!("No scores yet.");
+        return;
+    }
+    for (i, entry) in leaderboard.entries().iter().enumerate() {
+        println!("{:>2}. {:<16} {:>6}", i + 1, entry.name, entry.score);
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    if std::env::args().any(|arg| arg == "--scores") {
+        print_scores();
+        return;
+    }
+
+    let config = Config::load();
+    let scores = Leaderboard::load();
     enable_raw_mode().unwrap();
-    let (max_x, max_y) = size().unwrap();
+    let (term_w, term_h) = size().unwrap();
     execute!(stdout(), cursor::Hide, EnterAlternateScreen).unwrap();
-    let mut world = World::new(max_x, max_y);
-    if let Err(e) = world.run() {
+    // World::new assumes a field of at least MIN_TERMINAL_WIDTH x
+    // MIN_TERMINAL_HEIGHT (smaller underflows its `- 2` arithmetic), so seed
+    // it with a safe size and let handle_resize apply the real one,
+    // including the "too small" guard if the terminal doesn't measure up.
+    let mut world = World::new(
+        term_w.max(MIN_TERMINAL_WIDTH),
+        term_h.max(MIN_TERMINAL_HEIGHT),
+        config,
+        scores,
+    );
+    world.handle_resize(term_w, term_h).unwrap();
+    if let Err(e) = world.run().await {
         eprintln!("{e}");
     }
     execute!(stdout(), cursor::Show, LeaveAlternateScreen).unwrap();
     disable_raw_mode().unwrap();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn queue_turn_drops_reversal_of_pending_direction() {
+        let mut snake = Snake::new(5, 5, 1, 10, 10);
+        snake.head_dir = Right;
+        snake.queue_turn(Up);
+        snake.queue_turn(Down); // reverses the just-queued Up, not head_dir
+        assert_eq!(snake.pending_dirs, [Up]);
+    }
+
+    #[test]
+    fn queue_turn_drops_reversal_of_head_dir_when_queue_empty() {
+        let mut snake = Snake::new(5, 5, 1, 10, 10);
+        snake.head_dir = Right;
+        snake.queue_turn(Left);
+        assert!(snake.pending_dirs.is_empty());
+    }
+
+    #[test]
+    fn queue_turn_drops_repeat_of_last_queued_direction() {
+        let mut snake = Snake::new(5, 5, 1, 10, 10);
+        snake.head_dir = Right;
+        snake.queue_turn(Up);
+        snake.queue_turn(Up);
+        assert_eq!(snake.pending_dirs, [Up]);
+    }
+
+    fn make_world() -> World {
+        World::new(40, 20, Config::default(), Leaderboard::default())
+    }
+
+    #[test]
+    fn plan_move_heads_straight_for_an_unobstructed_target() {
+        let mut world = make_world();
+        world.snake.body = vec![(10, 10)];
+        world.snake.head_dir = Right;
+        world.target.x = 15;
+        world.target.y = 10;
+        assert_eq!(world.plan_move(), Some(Right));
+    }
+
+    #[test]
+    fn safest_direction_never_reverses_head_dir() {
+        let world = make_world();
+        let head = (world.min_x + 5, world.min_y + 5);
+        let dir = world.safest_direction(head, &HashSet::new());
+        assert_ne!(dir, Some(Left));
+    }
+
+    #[test]
+    fn safest_direction_avoids_a_sealed_pocket() {
+        let mut world = make_world();
+        world.snake.head_dir = Left; // excludes Right as a candidate, not the pocket below
+        let head = (15, 10);
+        let mut blocked = HashSet::new();
+        blocked.insert(head); // the snake's own old position, sealing the pocket shut
+        blocked.insert((14, 9));
+        blocked.insert((15, 8));
+        blocked.insert((16, 9));
+        let dir = world.safest_direction(head, &blocked);
+        assert_ne!(dir, Some(Up)); // Up only reaches the 1-cell pocket at (15, 9)
+    }
+
+    #[test]
+    fn queue_turn_stops_at_max_queued_directions() {
+        let mut snake = Snake::new(5, 5, 1, 10, 10);
+        snake.head_dir = Right;
+        // Alternate between perpendicular directions so none is rejected
+        // as a same-as-last or reverses-the-last-queued turn.
+        for i in 0..MAX_QUEUED_DIRECTIONS + 4 {
+            snake.queue_turn(if i % 2 == 0 { Up } else { Right });
+        }
+        assert_eq!(snake.pending_dirs.len(), MAX_QUEUED_DIRECTIONS);
+    }
+}
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const MAX_ENTRIES: usize = 10;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ScoreEntry {
+    pub name: String,
+    pub score: u64,
+    pub timestamp: u64,
+}
+
+/// Top-`MAX_ENTRIES` high scores, persisted as a small JSON file in the
+/// platform config dir so a run's score survives past the process exiting.
+#[derive(Serialize, Deserialize, Default)]
+pub struct Leaderboard {
+    entries: Vec<ScoreEntry>,
+}
+
+impl Leaderboard {
+    pub fn load() -> Self {
+        Self::path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        let path = Self::path().ok_or_else(|| anyhow::anyhow!("no config dir for scores file"))?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn qualifies(&self, score: u64) -> bool {
+        self.entries.len() < MAX_ENTRIES || self.entries.iter().any(|entry| entry.score < score)
+    }
+
+    pub fn insert(&mut self, name: String, score: u64) {
+        let entry = ScoreEntry {
+            name,
+            score,
+            timestamp: now(),
+        };
+        let pos = self.entries.partition_point(|e| e.score >= score);
+        self.entries.insert(pos, entry);
+        self.entries.truncate(MAX_ENTRIES);
+    }
+
+    pub fn entries(&self) -> &[ScoreEntry] {
+        &self.entries
+    }
+
+    fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("snake").join("scores.json"))
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_keeps_entries_sorted_highest_score_first() {
+        let mut board = Leaderboard::default();
+        board.insert("a".to_string(), 10);
+        board.insert("b".to_string(), 30);
+        board.insert("c".to_string(), 20);
+        let scores: Vec<u64> = board.entries().iter().map(|e| e.score).collect();
+        assert_eq!(scores, [30, 20, 10]);
+    }
+
+    #[test]
+    fn insert_breaks_ties_in_favor_of_the_earlier_entry() {
+        let mut board = Leaderboard::default();
+        board.insert("first".to_string(), 10);
+        board.insert("second".to_string(), 10);
+        let names: Vec<&str> = board.entries().iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["first", "second"]);
+    }
+
+    #[test]
+    fn insert_truncates_past_max_entries() {
+        let mut board = Leaderboard::default();
+        for score in 0..MAX_ENTRIES as u64 + 5 {
+            board.insert(format!("player{score}"), score);
+        }
+        assert_eq!(board.entries().len(), MAX_ENTRIES);
+        assert_eq!(board.entries()[0].score, MAX_ENTRIES as u64 + 4);
+    }
+
+    #[test]
+    fn qualifies_is_true_below_max_entries_regardless_of_score() {
+        let board = Leaderboard::default();
+        assert!(board.qualifies(0));
+    }
+
+    #[test]
+    fn qualifies_requires_beating_the_lowest_entry_once_full() {
+        let mut board = Leaderboard::default();
+        for score in 0..MAX_ENTRIES as u64 {
+            board.insert(format!("player{score}"), score);
+        }
+        assert!(board.qualifies(5));
+        assert!(!board.qualifies(0));
+    }
+}